@@ -1,10 +1,168 @@
-use std::path::PathBuf;
+use std::{env, ffi::OsString, path::PathBuf};
 
-use clap::Parser;
+use clap::{ColorChoice, CommandFactory, FromArgMatches, Parser, ValueEnum};
+
+use crate::error;
 
 #[derive(Parser)]
 pub struct Args {
     /// Path to the source code to compile
-    #[arg(help = "Path ng source code na ico-compile")]
-    input_path: PathBuf,
+    #[arg(
+        help = "Path ng source code na ico-compile",
+        required_unless_present = "ipaliwanag"
+    )]
+    pub input_path: Option<PathBuf>,
+
+    /// Print the long explanation of an error code, e.g. `T0001`
+    #[arg(
+        long,
+        value_name = "CODE",
+        conflicts_with = "input_path",
+        help = "Ipaliwanag ang isang error code, hal. `T0001`"
+    )]
+    pub ipaliwanag: Option<String>,
+
+    /// Language used for diagnostics
+    #[arg(
+        long,
+        value_enum,
+        env = "TOL_WIKA",
+        ignore_case = true,
+        default_value_t = Wika::Tl,
+        help = "Wikang gagamitin sa mga error"
+    )]
+    pub wika: Wika,
+
+    /// When to color diagnostics; `auto` honors NO_COLOR and checks whether stderr is a terminal
+    #[arg(
+        long,
+        value_enum,
+        ignore_case = true,
+        default_value_t = Kulay::Auto,
+        help = "Kung kailan kukulayan ang mga error"
+    )]
+    pub kulay: Kulay,
+
+    /// Never color diagnostics, same as `--kulay hindi`
+    #[arg(
+        long,
+        conflicts_with = "kulay",
+        help = "Huwag kulayan ang mga error (katulad ng `--kulay hindi`)"
+    )]
+    pub no_color: bool,
+}
+
+impl Args {
+    /// Color choice after folding `--no-color` into `--kulay`.
+    pub fn kulay(&self) -> Kulay {
+        if self.no_color {
+            Kulay::Hindi
+        } else {
+            self.kulay
+        }
+    }
+
+    /// Like [`Parser::parse`], but clap's own usage errors also follow `--kulay`.
+    pub fn parse_with_kulay() -> Self {
+        let kulay = Kulay::scan(env::args_os()).unwrap_or(Kulay::Auto);
+        let mut command = Self::command().color(kulay.into());
+        let matches = command.get_matches_mut();
+        Self::from_arg_matches(&matches).unwrap_or_else(|err| err.format(&mut command).exit())
+    }
+}
+
+/// When to color diagnostics, both ours (miette) and clap's
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Kulay {
+    Auto,
+    Palagi,
+    Hindi,
+}
+
+impl Kulay {
+    /// Finds `--kulay` or `--no-color` in argv before clap parses it, so
+    /// clap can be told whether to color its own errors.
+    fn scan(args: impl IntoIterator<Item = OsString>) -> Option<Self> {
+        let mut args = args.into_iter().skip(1);
+        while let Some(arg) = args.next() {
+            let Some(arg) = arg.to_str() else {
+                continue;
+            };
+            let value = match arg {
+                "--" => return None,
+                "--no-color" => return Some(Self::Hindi),
+                "--kulay" => args.next()?.into_string().ok()?,
+                _ => match arg.strip_prefix("--kulay=") {
+                    Some(value) => value.to_owned(),
+                    None => continue,
+                },
+            };
+            return Self::from_str(&value, true).ok();
+        }
+        None
+    }
+}
+
+impl From<Kulay> for ColorChoice {
+    fn from(kulay: Kulay) -> Self {
+        match kulay {
+            Kulay::Auto => ColorChoice::Auto,
+            Kulay::Palagi => ColorChoice::Always,
+            Kulay::Hindi => ColorChoice::Never,
+        }
+    }
+}
+
+/// Language used for diagnostics
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Wika {
+    Tl,
+    En,
+}
+
+impl From<Wika> for error::Wika {
+    fn from(wika: Wika) -> Self {
+        match wika {
+            Wika::Tl => error::Wika::Tl,
+            Wika::En => error::Wika::En,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(args: &[&str]) -> Option<Kulay> {
+        Kulay::scan(["tol"].iter().chain(args).map(OsString::from))
+    }
+
+    #[test]
+    fn scan_finds_kulay() {
+        assert_eq!(scan(&["--kulay", "hindi"]), Some(Kulay::Hindi));
+        assert_eq!(scan(&["x.tol", "--kulay=palagi"]), Some(Kulay::Palagi));
+        assert_eq!(scan(&["--kulay", "PALAGI"]), Some(Kulay::Palagi));
+        assert_eq!(scan(&["--no-color"]), Some(Kulay::Hindi));
+    }
+
+    #[test]
+    fn scan_rejects_bad_kulay() {
+        assert_eq!(scan(&["--kulay"]), None);
+        assert_eq!(scan(&["--kulay", "minsan"]), None);
+        assert_eq!(scan(&["--", "--kulay", "palagi"]), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn scan_skips_non_utf8_args() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let args = [
+            OsString::from("tol"),
+            OsString::from_vec(vec![0xff, 0xfe]),
+            OsString::from("--kulay"),
+            OsString::from("hindi"),
+        ];
+        assert_eq!(Kulay::scan(args), Some(Kulay::Hindi));
+    }
 }
@@ -1,10 +1,70 @@
-use std::io;
+use std::{error::Error as _, fmt, io};
 
 use miette::Diagnostic;
 use thiserror::Error;
 
-#[derive(Error, Debug, Diagnostic)]
+mod catalog;
+
+pub use catalog::{DiagnosticId, Wika, explain};
+
+#[derive(Error, Debug)]
 pub enum CompilerError {
-    #[error(transparent)]
+    #[error("{}", catalog::message(self, Wika::default()))]
     IO(#[from] io::Error),
+
+    #[error("{}", catalog::message(self, Wika::default()))]
+    UnknownCode(String),
+}
+
+impl CompilerError {
+    pub fn id(&self) -> DiagnosticId {
+        match self {
+            Self::IO(_) => DiagnosticId::T0001,
+            Self::UnknownCode(_) => DiagnosticId::T0002,
+        }
+    }
+
+    /// Wraps the error so it is rendered in `wika` instead of the default.
+    pub fn in_wika(self, wika: Wika) -> Localized {
+        Localized { error: self, wika }
+    }
+}
+
+impl Diagnostic for CompilerError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(self.id()))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(catalog::help(self.id(), Wika::default())))
+    }
+}
+
+/// A [`CompilerError`] rendered in a chosen language.
+#[derive(Debug)]
+pub struct Localized {
+    error: CompilerError,
+    wika: Wika,
+}
+
+impl fmt::Display for Localized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&catalog::message(&self.error, self.wika))
+    }
+}
+
+impl std::error::Error for Localized {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for Localized {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(catalog::help(self.error.id(), self.wika)))
+    }
 }
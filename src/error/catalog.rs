@@ -0,0 +1,144 @@
+use std::fmt;
+
+use super::CompilerError;
+use crate::prelude::MyResult;
+
+/// Language used for diagnostics.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Wika {
+    #[default]
+    Tl,
+    En,
+}
+
+macro_rules! diagnostic_ids {
+    ($($id:ident),* $(,)?) => {
+        /// Stable code shown in the header of every diagnostic.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum DiagnosticId {
+            $($id),*
+        }
+
+        impl DiagnosticId {
+            /// Every code, generated from the same list as the enum so none can be missed.
+            pub const ALL: &'static [DiagnosticId] = &[$(DiagnosticId::$id),*];
+
+            pub fn code(self) -> &'static str {
+                match self {
+                    $(Self::$id => stringify!($id)),*
+                }
+            }
+        }
+    };
+}
+
+diagnostic_ids!(T0001, T0002);
+
+impl DiagnosticId {
+    /// Looks up a code such as `T0001`, ignoring case.
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|id| id.code().eq_ignore_ascii_case(code))
+    }
+}
+
+impl fmt::Display for DiagnosticId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+pub(super) fn message(error: &CompilerError, wika: Wika) -> String {
+    match (error, wika) {
+        (CompilerError::IO(_), Wika::Tl) => "Hindi mabasa o maisulat ang isang file".to_owned(),
+        (CompilerError::IO(_), Wika::En) => "Could not read or write a file".to_owned(),
+        (CompilerError::UnknownCode(code), Wika::Tl) => {
+            format!("Walang paliwanag para sa code na `{code}`")
+        }
+        (CompilerError::UnknownCode(code), Wika::En) => {
+            format!("No explanation for the code `{code}`")
+        }
+    }
+}
+
+pub(super) fn help(id: DiagnosticId, wika: Wika) -> &'static str {
+    match (id, wika) {
+        (DiagnosticId::T0001, Wika::Tl) => {
+            "Tingnan kung umiiral ang file at kung may pahintulot na basahin o isulat ito"
+        }
+        (DiagnosticId::T0001, Wika::En) => {
+            "Check that the file exists and that it may be read or written"
+        }
+        (DiagnosticId::T0002, Wika::Tl) => {
+            "Ang mga code ay nasa anyong `T0001`, makikita sa simula ng bawat error"
+        }
+        (DiagnosticId::T0002, Wika::En) => {
+            "Codes look like `T0001` and appear at the start of every error"
+        }
+    }
+}
+
+fn explanation(id: DiagnosticId, wika: Wika) -> &'static str {
+    match (id, wika) {
+        (DiagnosticId::T0001, Wika::Tl) => include_str!("explanations/tl/T0001.md"),
+        (DiagnosticId::T0001, Wika::En) => include_str!("explanations/en/T0001.md"),
+        (DiagnosticId::T0002, Wika::Tl) => include_str!("explanations/tl/T0002.md"),
+        (DiagnosticId::T0002, Wika::En) => include_str!("explanations/en/T0002.md"),
+    }
+}
+
+/// Returns the long explanation printed by `tol --ipaliwanag <CODE>`.
+pub fn explain(code: &str, wika: Wika) -> MyResult<&'static str> {
+    DiagnosticId::from_code(code)
+        .map(|id| explanation(id, wika))
+        .ok_or_else(|| CompilerError::UnknownCode(code.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+
+    const WIKA: [Wika; 2] = [Wika::Tl, Wika::En];
+
+    fn sample(id: DiagnosticId) -> CompilerError {
+        match id {
+            DiagnosticId::T0001 => CompilerError::IO(io::Error::other("sira")),
+            DiagnosticId::T0002 => CompilerError::UnknownCode("T9999".to_owned()),
+        }
+    }
+
+    #[test]
+    fn every_code_is_translated_and_explained() {
+        for &id in DiagnosticId::ALL {
+            let error = sample(id);
+            assert_eq!(error.id(), id);
+
+            for wika in WIKA {
+                assert!(!message(&error, wika).is_empty(), "{id} {wika:?}");
+                assert!(!help(id, wika).is_empty(), "{id} {wika:?}");
+                assert!(
+                    !explain(id.code(), wika).unwrap().is_empty(),
+                    "{id} {wika:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn explain_ignores_case() {
+        assert_eq!(
+            explain("t0001", Wika::Tl).unwrap(),
+            explain("T0001", Wika::Tl).unwrap()
+        );
+    }
+
+    #[test]
+    fn explain_unknown_code() {
+        let error = explain("T9999", Wika::Tl).unwrap_err();
+        assert!(matches!(error, CompilerError::UnknownCode(code) if code == "T9999"));
+    }
+}
@@ -1,8 +1,8 @@
 #![allow(unused)]
 
 pub mod cmd;
+pub mod error;
 
-mod error;
 mod prelude;
 
 use prelude::*;
@@ -1,8 +1,40 @@
 #![allow(unused)]
 
-use clap::Parser;
-use tol::cmd::Args;
+use miette::MietteHandlerOpts;
+use tol::{
+    cmd::{Args, Kulay},
+    error,
+};
 
-fn main() {
-    let args = Args::parse();
+fn main() -> miette::Result<()> {
+    let args = Args::parse_with_kulay();
+    let wika = args.wika.into();
+    set_kulay(args.kulay())?;
+
+    if let Some(code) = &args.ipaliwanag {
+        let explanation = error::explain(code, wika).map_err(|err| err.in_wika(wika))?;
+        print!("{explanation}");
+        return Ok(());
+    }
+
+    Ok(())
+}
+
+fn set_kulay(kulay: Kulay) -> miette::Result<()> {
+    let color = match kulay {
+        Kulay::Auto => None,
+        Kulay::Palagi => Some(true),
+        Kulay::Hindi => Some(false),
+    };
+
+    miette::set_hook(Box::new(move |_| {
+        let opts = MietteHandlerOpts::new();
+        let opts = match color {
+            Some(color) => opts.color(color),
+            None => opts,
+        };
+        Box::new(opts.build())
+    }))?;
+
+    Ok(())
 }